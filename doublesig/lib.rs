@@ -17,8 +17,9 @@
 //! ### Withdrawals
 //!
 //! The user may opt out of this contract at anytime. This can be done using the
-//! `terminate` method. After doing so, the funds accumulated would be transferred
-//! to the user.
+//! `claim_funds` method. After doing so, the free balance is returned to the user and
+//! the accumulated savings are released to the `beneficiary`, which defaults to the
+//! user but may be set to a different account (e.g. for inheritance purposes).
 //!
 //! ### Deposits
 //! The creator of the contract, i.e the `sender`, can deposit funds to the payment
@@ -33,12 +34,88 @@
 
 #[ink::contract]
 mod doublesig {
+    use ink::prelude::vec::Vec;
+    use ink::storage::Mapping;
+
+    /// Identifier of the pot that `transfer_funds` routes fees into when the caller
+    /// does not select one explicitly.
+    const MAIN_POT_ID: u32 = 0;
+
+    /// A single named savings pot with its own unlock time. This borrows the
+    /// locks-as-overlay model from Substrate's balances pallet: several locks may sit
+    /// on top of the same free balance, each releasing independently at its own time.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(::scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Lock {
+        amount: Balance,
+        unlock_at: Timestamp,
+        label: [u8; 32],
+    }
 
     #[ink(storage)]
     pub struct DoubleSig {
         user: AccountId,
+        /// Recipient of the released savings on `claim_funds`, distinct from `user` who
+        /// receives the free (unsaved) balance. Defaults to `user` when unset.
+        beneficiary: Option<AccountId>,
         expiration: Timestamp,
-        amount_held: Balance,
+        fee_bps: u16,
+        /// Savings pots, keyed by caller-chosen pot id. `MAIN_POT_ID` always exists.
+        locks: Mapping<u32, Lock>,
+        /// Ids of every pot opened so far (empty ones included), so we can iterate `locks` without
+        /// relying on `Mapping` iteration (which ink does not provide).
+        pot_ids: Vec<u32>,
+        /// Running total of `locks[*].amount`, kept in sync on every mutation so
+        /// `free()` doesn't need to walk `pot_ids`.
+        total_held: Balance,
+        /// Remaining amount each spender is approved to move via `transfer_funds_from`.
+        allowances: Mapping<AccountId, Balance>,
+    }
+
+    /// Emitted whenever `transfer_funds` successfully sends funds to a `destination`.
+    #[ink(event)]
+    pub struct FundsTransferred {
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        to: AccountId,
+        amount: Balance,
+    }
+
+    /// Emitted whenever a fee is deducted and added to the savings pot.
+    #[ink(event)]
+    pub struct FeeAccrued {
+        amount: Balance,
+        total_held: Balance,
+    }
+
+    /// Emitted whenever the saver withdraws a pot's accumulated savings via `withdraw_pot`.
+    #[ink(event)]
+    pub struct SavingsWithdrawn {
+        #[ink(topic)]
+        to: AccountId,
+        amount: Balance,
+    }
+
+    /// Emitted when the contract is terminated via `claim_funds`.
+    #[ink(event)]
+    pub struct ContractTerminated {
+        #[ink(topic)]
+        beneficiary: AccountId,
+        returned: Balance,
+    }
+
+    /// Emitted whenever `approve` sets a spender's allowance.
+    #[ink(event)]
+    pub struct Approval {
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        spender: AccountId,
+        value: Balance,
     }
 
     /// Errors that can occur upon calling this contract.
@@ -55,50 +132,251 @@ mod doublesig {
             funds_to_transfer: Balance,
             existential_deposit: Balance,
         },
-        TransferAmountTooLarge,
         WithdrawalFailed,
+        FeeCalculationOverflow,
+        /// Returned when a pot id was never created via `open_pot`.
+        PotNotFound,
+        /// Returned by `open_pot` when the given pot id is already in use.
+        PotAlreadyExists,
+        /// Returned when `transfer_funds_from` is called for more than the caller's
+        /// remaining allowance.
+        InsufficientAllowance,
+        /// Returned when the downstream call made by `transfer_funds_call` reverts.
+        /// Because the transferred value rides along with the same sub-call, the
+        /// runtime rolls it back automatically and no local state is mutated.
+        CrossContractCallFailed,
+        /// Returned by `set_beneficiary` once the contract's expiration has passed.
+        ExpirationPassed,
     }
 
     /// Type alias for the contract's `Result` type.
     pub type Result<T> = core::result::Result<T, Error>;
 
-    const FEE: f64 = 0.03; // 3%
+    /// Default fee, in basis points (1/100th of a percent), i.e. 300 = 3%.
+    const DEFAULT_FEE_BPS: u16 = 300;
 
     impl DoubleSig {
         /// Creates a new instance of this contract.
         /// `expiration` refers to how long you'd, like to keep your funds in this contract.
         /// After the expiration period, you are allowed to withdraw the funds.
+        /// `beneficiary` receives the released savings on `claim_funds`; pass `None` to
+        /// have them go to the `user` as before.
         #[ink(constructor, payable)]
-        pub fn new(expiration: Timestamp) -> Self {
+        pub fn new(expiration: Timestamp, beneficiary: Option<AccountId>) -> Self {
+            let mut locks = Mapping::default();
+            locks.insert(
+                MAIN_POT_ID,
+                &Lock {
+                    amount: 0,
+                    unlock_at: expiration,
+                    label: [0u8; 32],
+                },
+            );
             Self {
                 user: Self::env().caller(),
+                beneficiary,
                 expiration,
-                amount_held: 0,
+                fee_bps: DEFAULT_FEE_BPS,
+                locks,
+                pot_ids: ink::prelude::vec![MAIN_POT_ID],
+                total_held: 0,
+                allowances: Mapping::default(),
+            }
+        }
+
+        /// Approves `spender` to move up to `value` via `transfer_funds_from`, replacing
+        /// any previous allowance. Owner-only.
+        #[ink(message)]
+        pub fn approve(&mut self, spender: AccountId, value: Balance) -> Result<()> {
+            if self.env().caller() != self.user {
+                return Err(Error::CallerIsNotOwner);
             }
+            self.allowances.insert(spender, &value);
+            self.env().emit_event(Approval {
+                owner: self.user,
+                spender,
+                value,
+            });
+            Ok(())
         }
 
-        /// Transfer `amount` to specified `destination`. An additional 3% of the transaction
-        /// would be deducted and stored.
+        /// Returns the remaining amount `spender` is approved to move.
+        #[ink(message)]
+        pub fn allowance(&self, spender: AccountId) -> Balance {
+            self.allowances.get(spender).unwrap_or_default()
+        }
+
+        /// Lets an approved `spender` move funds on the owner's behalf, up to its
+        /// remaining allowance, without holding the owner key. `transfer_funds` itself
+        /// is owner-only; this is the delegated path guardians/automations use instead.
+        /// The usual savings deduction still applies.
+        #[ink(message)]
+        pub fn transfer_funds_from(
+            &mut self,
+            destination: AccountId,
+            amount: Balance,
+            pot_id: Option<u32>,
+        ) -> Result<()> {
+            let spender = self.env().caller();
+            let remaining = self.allowances.get(spender).unwrap_or_default();
+            if amount > remaining {
+                return Err(Error::InsufficientAllowance);
+            }
+            self.do_transfer_funds(destination, amount, pot_id)?;
+            self.allowances.insert(spender, &(remaining - amount));
+            Ok(())
+        }
+
+        /// Transfer `amount` to a recipient contract and invoke `selector` on it with
+        /// `data`, modeled on NEAR's `ft_transfer_call`/resolve pattern: `amount` rides
+        /// along with the call as its transferred value, so if the downstream call
+        /// reverts the runtime rolls that transfer back automatically and this
+        /// contract's state, including the fee accrual, is left untouched. On success
+        /// the callee reports how much of `amount` it actually consumed; the savings
+        /// deduction is applied only to that portion, on the assumption that a
+        /// cooperative callee sends any unused remainder back as part of the same
+        /// call. Owner-only, like `transfer_funds`.
         ///
         /// # Errors
         ///
+        /// - Returns `CallerIsNotOwner` if the caller is not the contract's `user`.
+        /// - Returns `FeeCalculationOverflow` if computing the fee overflows `Balance`.
+        /// - Returns `PotNotFound` if `pot_id` was not created via `open_pot`.
+        /// - Returns `InsufficientFunds` if spending `amount` would dip the contract's
+        ///   balance below the minimum balance.
+        /// - Returns `CrossContractCallFailed` if the downstream call reverts or its
+        ///   return value cannot be decoded.
+        #[ink(message)]
+        pub fn transfer_funds_call(
+            &mut self,
+            destination: AccountId,
+            amount: Balance,
+            selector: [u8; 4],
+            data: Vec<u8>,
+            gas_limit: u64,
+            pot_id: Option<u32>,
+        ) -> Result<Balance> {
+            if self.env().caller() != self.user {
+                return Err(Error::CallerIsNotOwner);
+            }
+            let pot_id = pot_id.unwrap_or(MAIN_POT_ID);
+            let mut lock = self.locks.get(pot_id).ok_or(Error::PotNotFound)?;
+
+            // Same guard as `transfer_funds`, since the full `amount` rides along with
+            // the call below.
+            let balance = self.get_balance();
+            let amount_to_deduct = self.calculate_fee(amount)?;
+            let current_balance = balance
+                .checked_sub(self.env().minimum_balance())
+                .and_then(|res| res.checked_sub(amount_to_deduct))
+                .unwrap_or_default();
+            if current_balance <= amount {
+                return Err(Error::InsufficientFunds {
+                    total_balance: self.get_balance(),
+                    funds_to_transfer: amount,
+                    potential_balance: current_balance,
+                    existential_deposit: self.env().minimum_balance(),
+                });
+            }
+
+            let consumed = ink::env::call::build_call::<ink::env::DefaultEnvironment>()
+                .call(destination)
+                .gas_limit(gas_limit)
+                .transferred_value(amount)
+                .exec_input(
+                    ink::env::call::ExecutionInput::new(ink::env::call::Selector::new(selector))
+                        .push_arg(data),
+                )
+                .returns::<Balance>()
+                .try_invoke()
+                .map_err(|_| Error::CrossContractCallFailed)?
+                .map_err(|_| Error::CrossContractCallFailed)?
+                .min(amount);
+
+            let amount_to_deduct = self.calculate_fee(consumed)?;
+            lock.amount += amount_to_deduct;
+            self.locks.insert(pot_id, &lock);
+            self.total_held += amount_to_deduct;
+            self.env().emit_event(FundsTransferred {
+                from: self.env().caller(),
+                to: destination,
+                amount: consumed,
+            });
+            self.env().emit_event(FeeAccrued {
+                amount: amount_to_deduct,
+                total_held: self.total_held,
+            });
+            Ok(consumed)
+        }
+
+        /// Opens a new, initially empty, savings pot that `transfer_funds` can route
+        /// fees into. Returns `PotAlreadyExists` if `pot_id` is already in use.
+        #[ink(message)]
+        pub fn open_pot(
+            &mut self,
+            pot_id: u32,
+            unlock_at: Timestamp,
+            label: [u8; 32],
+        ) -> Result<()> {
+            if self.env().caller() != self.user {
+                return Err(Error::CallerIsNotOwner);
+            }
+            if self.locks.contains(pot_id) {
+                return Err(Error::PotAlreadyExists);
+            }
+            self.locks.insert(
+                pot_id,
+                &Lock {
+                    amount: 0,
+                    unlock_at,
+                    label,
+                },
+            );
+            self.pot_ids.push(pot_id);
+            Ok(())
+        }
+
+        /// Transfer `amount` to specified `destination`. An additional fee, proportional to
+        /// `fee_bps`, would be deducted and stored in `pot_id` (defaulting to the main pot).
+        /// Owner-only; approved spenders should use `transfer_funds_from` instead.
+        ///
+        /// # Errors
+        ///
+        /// - Returns `CallerIsNotOwner` if the caller is not the contract's `user`.
+        /// - Returns `FeeCalculationOverflow` if computing the fee overflows `Balance`.
+        /// - Returns `PotNotFound` if `pot_id` was not created via `open_pot`.
         /// - Panics in case the requested transfer exceeds the contract balance.
         /// - Panics in case the requested transfer would have brought this
         ///   contract's balance below the minimum balance (i.e. the chain's
         ///   existential deposit).
         /// - Panics in case the transfer failed for another reason.
         #[ink(message)]
-        pub fn transfer_funds(&mut self, destination: AccountId, amount: Balance) -> Result<()> {
+        pub fn transfer_funds(
+            &mut self,
+            destination: AccountId,
+            amount: Balance,
+            pot_id: Option<u32>,
+        ) -> Result<()> {
+            if self.env().caller() != self.user {
+                return Err(Error::CallerIsNotOwner);
+            }
+            self.do_transfer_funds(destination, amount, pot_id)
+        }
+
+        /// Shared implementation behind `transfer_funds` and `transfer_funds_from`.
+        /// Callers are responsible for authorizing the caller before reaching here
+        /// (owner check or allowance check, respectively).
+        fn do_transfer_funds(
+            &mut self,
+            destination: AccountId,
+            amount: Balance,
+            pot_id: Option<u32>,
+        ) -> Result<()> {
+            let pot_id = pot_id.unwrap_or(MAIN_POT_ID);
+            let mut lock = self.locks.get(pot_id).ok_or(Error::PotNotFound)?;
             // ensure the amount held is greater than the contract's balance
             let balance = self.get_balance();
-            // since fractions aren't supported, use the `ceil` value
-            // Therefore the minimum fee is 1 unit
-            let amount_to_deduct = {
-                if amount > f64::MAX as Balance {
-                    return Err(Error::TransferAmountTooLarge);
-                };
-                (amount as f64 * FEE).ceil() as Balance
-            };
+            let amount_to_deduct = self.calculate_fee(amount)?;
             let current_balance = balance
                 .checked_sub(self.env().minimum_balance())
                 .and_then(|res| res.checked_sub(amount_to_deduct))
@@ -118,75 +396,154 @@ mod doublesig {
                      contract's balance below minimum balance."
                 )
             }
-            self.amount_held += amount_to_deduct;
+            lock.amount += amount_to_deduct;
+            self.locks.insert(pot_id, &lock);
+            self.total_held += amount_to_deduct;
+            self.env().emit_event(FundsTransferred {
+                from: self.env().caller(),
+                to: destination,
+                amount,
+            });
+            self.env().emit_event(FeeAccrued {
+                amount: amount_to_deduct,
+                total_held: self.total_held,
+            });
             Ok(())
         }
 
         /// Withdraw all funds in the contract and terminate the contract.
-        /// This returns an error when the expiration date has not reached
+        /// This returns `NotYetExpired` unless every pot has unlocked. The free
+        /// (unsaved) balance goes to `user` and the accumulated savings go to the
+        /// `beneficiary` (defaulting to `user`), in that order, before termination.
         #[ink(message)]
         pub fn claim_funds(&mut self) -> Result<()> {
             if self.env().caller() != self.user {
                 return Err(Error::CallerIsNotOwner);
             }
             let now = self.env().block_timestamp();
-            if now < self.expiration {
-                return Err(Error::NotYetExpired);
+            for pot_id in &self.pot_ids {
+                let lock = self.locks.get(*pot_id).unwrap_or(Lock {
+                    amount: 0,
+                    unlock_at: now,
+                    label: [0u8; 32],
+                });
+                if now < lock.unlock_at {
+                    return Err(Error::NotYetExpired);
+                }
             }
-            self.env().terminate_contract(self.user);
+            let beneficiary = self.beneficiary.unwrap_or(self.user);
+            let free = self.free();
+            // Capture what's actually being paid out before the transfers drain the
+            // contract down to just the existential deposit `terminate_contract` sweeps.
+            let returned = free + self.total_held;
+            if free > 0 && self.env().transfer(self.user, free).is_err() {
+                panic!(
+                    "requested transfer failed. this can be the case if the contract does not\
+                     have sufficient free funds or if the transfer would have brought the\
+                     contract's balance below minimum balance."
+                )
+            }
+            if self.total_held > 0 && self.env().transfer(beneficiary, self.total_held).is_err() {
+                panic!(
+                    "requested transfer failed. this can be the case if the contract does not\
+                     have sufficient free funds or if the transfer would have brought the\
+                     contract's balance below minimum balance."
+                )
+            }
+            self.env().emit_event(ContractTerminated {
+                beneficiary,
+                returned,
+            });
+            self.env().terminate_contract(beneficiary);
+        }
+
+        /// Sets the `beneficiary` who receives the released savings on `claim_funds`.
+        /// Owner-only, and only callable before `expiration` so the payout split can't
+        /// be changed once the saver is already eligible to claim.
+        #[ink(message)]
+        pub fn set_beneficiary(&mut self, beneficiary: Option<AccountId>) -> Result<()> {
+            if self.env().caller() != self.user {
+                return Err(Error::CallerIsNotOwner);
+            }
+            if self.env().block_timestamp() >= self.expiration {
+                return Err(Error::ExpirationPassed);
+            }
+            self.beneficiary = beneficiary;
+            Ok(())
+        }
+
+        /// Returns the current beneficiary, falling back to `user` when unset.
+        #[ink(message)]
+        pub fn get_beneficiary(&self) -> AccountId {
+            self.beneficiary.unwrap_or(self.user)
         }
 
-        /// Transfer all savings to `senders` account
+        /// Release the savings held in `pot_id` to the owner, provided its `unlock_at`
+        /// has passed. Other pots remain locked.
         ///
         /// # Errors
         /// Ideally this method doesn't panic. Please report any panics
-        pub fn withdraw_savings(&mut self) -> Result<()> {
+        #[ink(message)]
+        pub fn withdraw_pot(&mut self, pot_id: u32) -> Result<()> {
             if self.env().caller() != self.user {
                 return Err(Error::CallerIsNotOwner);
             }
+            let lock = self.locks.get(pot_id).ok_or(Error::PotNotFound)?;
+            if lock.amount == 0 {
+                // Nothing to release; avoid a no-op transfer and a noise event.
+                return Ok(());
+            }
             let now = self.env().block_timestamp();
-            if now < self.expiration {
+            if now < lock.unlock_at {
                 return Err(Error::NotYetExpired);
             }
-            let remainder = self
+            let remaining_balance = self
                 .get_balance()
-                .checked_sub(self.amount_held)
+                .checked_sub(lock.amount)
                 .unwrap_or_default();
-            if remainder < self.env().minimum_balance() {
+            if remaining_balance < self.env().minimum_balance() {
                 ink::env::debug_println!(
                     "Balance would fall below existential deposit. \
                     Terminate contract to withdraw all funds"
                 );
                 return Err(Error::WithdrawalFailed);
             }
-            if self
-                .env()
-                .transfer(self.env().caller(), self.amount_held)
-                .map(|_| self.amount_held = 0)
-                .is_err()
-            {
+            if self.env().transfer(self.env().caller(), lock.amount).is_err() {
                 panic!(
                     "requested transfer failed. this can be the case if the contract does not\
                      have sufficient free funds or if the transfer would have brought the\
                      contract's balance below minimum balance."
                 )
             }
+            self.total_held = self.total_held.saturating_sub(lock.amount);
+            self.locks.insert(pot_id, &Lock { amount: 0, ..lock });
+            self.env().emit_event(SavingsWithdrawn {
+                to: self.env().caller(),
+                amount: lock.amount,
+            });
             Ok(())
         }
 
-        /// Get the current spendable amount (`free balance`)
+        /// Get the current spendable amount (`free balance`), i.e. the balance not
+        /// locked in any savings pot.
         #[ink(message)]
         pub fn free(&self) -> Balance {
             self.get_balance()
                 .checked_sub(self.env().minimum_balance())
-                .and_then(|res| res.checked_sub(self.amount_held))
+                .and_then(|res| res.checked_sub(self.total_held))
                 .unwrap_or_default()
         }
 
-        /// Get the total value of the funds which has been saved
+        /// Get the total value of the funds which has been saved, across all pots.
         #[ink(message)]
         pub fn amount_stored(&self) -> Balance {
-            self.amount_held
+            self.total_held
+        }
+
+        /// Returns the lock details for `pot_id`, or `None` if it was never opened.
+        #[ink(message)]
+        pub fn get_pot(&self, pot_id: u32) -> Option<Lock> {
+            self.locks.get(pot_id)
         }
 
         /// Returns the total `balance` of the contract.
@@ -200,6 +557,29 @@ mod doublesig {
         pub fn get_expiration(&self) -> Timestamp {
             self.expiration
         }
+
+        /// Returns the fee, in basis points, deducted on every `transfer_funds` call.
+        #[ink(message)]
+        pub fn get_fee_bps(&self) -> u16 {
+            self.fee_bps
+        }
+
+        /// Computes the fee owed on `amount` at `self.fee_bps`, rounding up to the nearest
+        /// unit and enforcing a minimum fee of 1 whenever `amount > 0`.
+        fn calculate_fee(&self, amount: Balance) -> Result<Balance> {
+            if amount == 0 {
+                return Ok(0);
+            }
+            let scaled = amount
+                .checked_mul(self.fee_bps as Balance)
+                .ok_or(Error::FeeCalculationOverflow)?;
+            let fee = if scaled % 10_000 == 0 {
+                scaled / 10_000
+            } else {
+                scaled / 10_000 + 1
+            };
+            Ok(fee.max(1))
+        }
     }
 
     #[cfg(test)]
@@ -232,7 +612,7 @@ mod doublesig {
             let accounts = default_accounts();
             set_sender(accounts.alice);
             set_balance(contract_id(), initial_balance);
-            DoubleSig::new(1000)
+            DoubleSig::new(1000, None)
         }
 
         fn advance_block() {
@@ -244,10 +624,11 @@ mod doublesig {
             let contract_balance = 100_000_000;
             let accounts = default_accounts();
             let mut smart_contract = create_contract(contract_balance);
-            set_sender(accounts.eve);
             set_balance(accounts.eve, 0);
+            // `transfer_funds` is owner-only; `create_contract` made alice the owner and
+            // she is still the caller here.
             smart_contract
-                .transfer_funds(accounts.eve, 2_000_000)
+                .transfer_funds(accounts.eve, 2_000_000, None)
                 .unwrap();
             assert_eq!(get_balance(accounts.eve), 2_000_000);
         }
@@ -258,20 +639,18 @@ mod doublesig {
             let accounts = default_accounts();
             let mut smart_contract = create_contract(contract_balance);
             // send initial funds
-            set_sender(accounts.eve);
             set_balance(accounts.eve, 0);
             smart_contract
-                .transfer_funds(accounts.eve, 2_000_000)
+                .transfer_funds(accounts.eve, 2_000_000, None)
                 .unwrap();
             assert_eq!(get_balance(accounts.eve), 2_000_000);
             assert_eq!(smart_contract.amount_stored(), 60_000); // 3% of transaction (2 million)
             assert_eq!(smart_contract.get_balance(), 98_000_000);
 
             // send larger funds
-            set_sender(accounts.bob);
             set_balance(accounts.bob, 0);
             smart_contract
-                .transfer_funds(accounts.bob, 90_000_000)
+                .transfer_funds(accounts.bob, 90_000_000, None)
                 .unwrap();
             assert_eq!(get_balance(accounts.bob), 90_000_000);
             assert_eq!(smart_contract.amount_stored(), 2_760_000); //2.7 mil + 60k (initial)
@@ -284,9 +663,8 @@ mod doublesig {
             let accounts = default_accounts();
             let mut smart_contract = create_contract(contract_balance);
             // send initial large funds
-            set_sender(accounts.eve);
             set_balance(accounts.eve, 0);
-            let transaction = smart_contract.transfer_funds(accounts.eve, 98_000_000);
+            let transaction = smart_contract.transfer_funds(accounts.eve, 98_000_000, None);
 
             assert_eq!(
                 transaction.unwrap_err(),
@@ -304,21 +682,63 @@ mod doublesig {
             let contract_balance = 100_000_000;
             let accounts = default_accounts();
             set_sender(accounts.alice);
+            // The test env's default contract account is alice; move the contract to its
+            // own account so the payouts to alice can be told apart from its balance.
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.charlie);
+            set_balance(accounts.alice, 0);
             set_balance(contract_id(), contract_balance);
             let expiration = 1;
-            let mut smart_contract = DoubleSig::new(expiration);
+            let mut smart_contract = DoubleSig::new(expiration, None);
             smart_contract
-                .transfer_funds(accounts.bob, 9_000_000)
+                .transfer_funds(accounts.bob, 9_000_000, None)
                 .unwrap();
             assert_eq!(smart_contract.amount_stored(), 270_000); //3% of 9 mill
             let total_balance_left = 91_000_000;
+            // `claim_funds` pays the free balance and the savings out via explicit
+            // transfers before terminating, so only the existential deposit is left
+            // for `terminate_contract` itself to sweep.
+            let existential_deposit = 1_000_000;
             advance_block();
             let should_close = move || smart_contract.claim_funds().unwrap();
             ink::env::test::assert_contract_termination::<ink::env::DefaultEnvironment, _>(
                 should_close,
                 accounts.alice,
-                total_balance_left,
+                existential_deposit,
             );
+            assert_eq!(get_balance(accounts.alice), total_balance_left);
+        }
+
+        #[ink::test]
+        fn test_transfer_funds_requires_owner() {
+            let contract_balance = 100_000_000;
+            let accounts = default_accounts();
+            let mut smart_contract = create_contract(contract_balance);
+            set_sender(accounts.eve);
+            assert_eq!(
+                smart_contract.transfer_funds(accounts.eve, 2_000_000, None),
+                Err(Error::CallerIsNotOwner)
+            );
+        }
+
+        #[ink::test]
+        fn test_transfer_funds_from_respects_allowance() {
+            let contract_balance = 100_000_000;
+            let accounts = default_accounts();
+            let mut smart_contract = create_contract(contract_balance);
+            // alice is still the caller from `create_contract`.
+            smart_contract.approve(accounts.eve, 2_000_000).unwrap();
+
+            set_sender(accounts.eve);
+            set_balance(accounts.bob, 0);
+            assert_eq!(
+                smart_contract.transfer_funds_from(accounts.bob, 3_000_000, None),
+                Err(Error::InsufficientAllowance)
+            );
+            smart_contract
+                .transfer_funds_from(accounts.bob, 2_000_000, None)
+                .unwrap();
+            assert_eq!(get_balance(accounts.bob), 2_000_000);
+            assert_eq!(smart_contract.allowance(accounts.eve), 0);
         }
     }
 }